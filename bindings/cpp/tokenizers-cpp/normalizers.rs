@@ -6,6 +6,14 @@ pub mod ffi {
         True,
     }
 
+    pub enum UnicodeNormalizationMode {
+        NFC,
+        NFD,
+        NFKC,
+        NFKD,
+        NmtNfkc,
+    }
+
     extern "C++" {
         include!("tokenizers-cpp/normalizers.h");
     }
@@ -16,6 +24,7 @@ pub mod ffi {
         type Normalizer;
 
         fn normalized_string(str: &str) -> Box<NormalizedString>;
+        fn normalized_string_utf16(input: &[u16]) -> Box<NormalizedString>;
 
         fn bert_normalizer(
             clean_text: bool,
@@ -36,10 +45,20 @@ pub mod ffi {
 
         fn nfkd_normalizer() -> Box<Normalizer>;
 
+        fn unicode_normalizer(mode: UnicodeNormalizationMode) -> Box<Normalizer>;
+
         fn lowercase_normalizer() -> Box<Normalizer>;
 
         fn nmt_normalizer() -> Box<Normalizer>;
 
+        fn swedish_recomposition_normalizer() -> Box<Normalizer>;
+
+        fn russian_normalizer() -> Box<Normalizer>;
+
+        fn control_char_normalizer() -> Box<Normalizer>;
+
+        fn nonspacing_mark_normalizer() -> Box<Normalizer>;
+
         fn precompiled_normalizer(precompiled_charsmap: &[u8]) -> Result<Box<Normalizer>>;
 
         fn replace_literal_normalizer(pattern: &str, content: &str) -> Result<Box<Normalizer>>;
@@ -49,9 +68,31 @@ pub mod ffi {
         fn sequence_normalizer(normalizers: Vec<Normalizer>) -> Box<Normalizer>;
 
         fn normalize(normalizer: &Normalizer, normalized: &mut NormalizedString) -> Result<()>;
+        fn normalize_utf16(normalizer: &Normalizer, input: &[u16]) -> Result<Vec<u16>>;
+
+        fn is_normalized(normalizer: &Normalizer, str: &str) -> Result<bool>;
+        fn is_normalized_up_to(normalizer: &Normalizer, str: &str) -> Result<usize>;
 
         fn get_normalized(normalized: &NormalizedString) -> &str;
         fn get_original(normalized: &NormalizedString) -> &str;
+
+        fn normalized_to_original_range(normalized: &NormalizedString, start: usize, end: usize) -> Vec<usize>;
+        fn original_to_normalized_range(normalized: &NormalizedString, start: usize, end: usize) -> Vec<usize>;
+
+        fn normalized_to_original_range_utf16(
+            normalized: &NormalizedString,
+            original_utf16: &[u16],
+            normalized_utf16: &[u16],
+            start: usize,
+            end: usize,
+        ) -> Vec<usize>;
+        fn original_to_normalized_range_utf16(
+            normalized: &NormalizedString,
+            original_utf16: &[u16],
+            normalized_utf16: &[u16],
+            start: usize,
+            end: usize,
+        ) -> Vec<usize>;
     }
 }
 
@@ -59,7 +100,8 @@ use derive_more::{Deref, DerefMut};
 use tk::{
     normalizers::{
         replace::ReplacePattern, BertNormalizer, Lowercase, Nmt, Precompiled, Replace, Sequence,
-        Strip, StripAccents, NFC, NFD, NFKC, NFKD,
+        ControlChar, NonspacingMark, Russian, Strip, StripAccents, SwedishRecomposition, NFC, NFD,
+        NFKC, NFKD,
     },
     Normalizer as NormalizerTrait, Result,
 };
@@ -76,12 +118,39 @@ impl NormalizerTrait for Normalizer {
     }
 }
 
-use ffi::BertStripAccents;
+use ffi::{BertStripAccents, UnicodeNormalizationMode};
 
 fn normalized_string(str: &str) -> Box<NormalizedString> {
     Box::new(NormalizedString(str.into()))
 }
 
+fn normalized_string_utf16(input: &[u16]) -> Box<NormalizedString> {
+    let decoded: String = char::decode_utf16(input.iter().copied())
+        .map(|r| r.unwrap_or(char::REPLACEMENT_CHARACTER))
+        .collect();
+    normalized_string(&decoded)
+}
+
+fn utf16_offset_to_utf8(utf16: &[u16], utf16_offset: usize) -> usize {
+    char::decode_utf16(utf16.iter().copied().take(utf16_offset))
+        .map(|r| r.unwrap_or(char::REPLACEMENT_CHARACTER).len_utf8())
+        .sum()
+}
+
+fn utf8_offset_to_utf16(utf16: &[u16], utf8_offset: usize) -> usize {
+    let mut utf8_pos = 0;
+    let mut utf16_pos = 0;
+    for c in char::decode_utf16(utf16.iter().copied()) {
+        if utf8_pos >= utf8_offset {
+            break;
+        }
+        let c = c.unwrap_or(char::REPLACEMENT_CHARACTER);
+        utf8_pos += c.len_utf8();
+        utf16_pos += c.len_utf16();
+    }
+    utf16_pos
+}
+
 fn make_normalizer<N: Into<tk::NormalizerWrapper>>(normalizer: N) -> Box<Normalizer> {
     Box::new(Normalizer(normalizer.into()))
 }
@@ -130,6 +199,22 @@ fn nfkd_normalizer() -> Box<Normalizer> {
     make_normalizer(NFKD)
 }
 
+fn unicode_normalizer(mode: UnicodeNormalizationMode) -> Box<Normalizer> {
+    match mode {
+        UnicodeNormalizationMode::NFC => make_normalizer(NFC),
+        UnicodeNormalizationMode::NFD => make_normalizer(NFD),
+        UnicodeNormalizationMode::NFKC => make_normalizer(NFKC),
+        UnicodeNormalizationMode::NFKD => make_normalizer(NFKD),
+        UnicodeNormalizationMode::NmtNfkc => {
+            make_normalizer(Sequence::new(vec![Nmt.into(), NFKC.into()]))
+        }
+        // An out-of-range discriminant can cross the FFI boundary from C++; fall back to NFC
+        // rather than aborting the process, mirroring `bert_normalizer`'s handling of an
+        // unrecognized `BertStripAccents`.
+        _ => make_normalizer(NFC),
+    }
+}
+
 fn lowercase_normalizer() -> Box<Normalizer> {
     make_normalizer(Lowercase)
 }
@@ -138,6 +223,22 @@ fn nmt_normalizer() -> Box<Normalizer> {
     make_normalizer(Nmt)
 }
 
+fn swedish_recomposition_normalizer() -> Box<Normalizer> {
+    make_normalizer(SwedishRecomposition::default())
+}
+
+fn russian_normalizer() -> Box<Normalizer> {
+    make_normalizer(Russian::default())
+}
+
+fn control_char_normalizer() -> Box<Normalizer> {
+    make_normalizer(ControlChar::default())
+}
+
+fn nonspacing_mark_normalizer() -> Box<Normalizer> {
+    make_normalizer(NonspacingMark::default())
+}
+
 fn precompiled_normalizer(precompiled_charsmap: &[u8]) -> Result<Box<Normalizer>> {
     Ok(make_normalizer(Precompiled::from(precompiled_charsmap)?))
 }
@@ -166,6 +267,12 @@ fn normalize(normalizer: &Normalizer, normalized: &mut NormalizedString) -> Resu
     normalizer.normalize(normalized)
 }
 
+fn normalize_utf16(normalizer: &Normalizer, input: &[u16]) -> Result<Vec<u16>> {
+    let mut normalized = normalized_string_utf16(input);
+    normalizer.normalize(&mut normalized)?;
+    Ok(normalized.get().encode_utf16().collect())
+}
+
 fn get_normalized(normalized: &NormalizedString) -> &str {
     normalized.get()
 }
@@ -173,3 +280,136 @@ fn get_normalized(normalized: &NormalizedString) -> &str {
 fn get_original(normalized: &NormalizedString) -> &str {
     normalized.get_original()
 }
+
+fn normalized_to_original_range(normalized: &NormalizedString, start: usize, end: usize) -> Vec<usize> {
+    match normalized.range_original(start..end) {
+        Some(range) => vec![range.start, range.end],
+        None => vec![],
+    }
+}
+
+fn original_to_normalized_range(normalized: &NormalizedString, start: usize, end: usize) -> Vec<usize> {
+    match normalized.range_normalized(start..end) {
+        Some(range) => vec![range.start, range.end],
+        None => vec![],
+    }
+}
+
+fn normalized_to_original_range_utf16(
+    normalized: &NormalizedString,
+    original_utf16: &[u16],
+    normalized_utf16: &[u16],
+    start: usize,
+    end: usize,
+) -> Vec<usize> {
+    let utf8_start = utf16_offset_to_utf8(normalized_utf16, start);
+    let utf8_end = utf16_offset_to_utf8(normalized_utf16, end);
+    match normalized.range_original(utf8_start..utf8_end) {
+        Some(range) => vec![
+            utf8_offset_to_utf16(original_utf16, range.start),
+            utf8_offset_to_utf16(original_utf16, range.end),
+        ],
+        None => vec![],
+    }
+}
+
+fn original_to_normalized_range_utf16(
+    normalized: &NormalizedString,
+    original_utf16: &[u16],
+    normalized_utf16: &[u16],
+    start: usize,
+    end: usize,
+) -> Vec<usize> {
+    let utf8_start = utf16_offset_to_utf8(original_utf16, start);
+    let utf8_end = utf16_offset_to_utf8(original_utf16, end);
+    match normalized.range_normalized(utf8_start..utf8_end) {
+        Some(range) => vec![
+            utf8_offset_to_utf16(normalized_utf16, range.start),
+            utf8_offset_to_utf16(normalized_utf16, range.end),
+        ],
+        None => vec![],
+    }
+}
+
+fn quick_is_normalized(wrapper: &tk::NormalizerWrapper, str: &str) -> Option<bool> {
+    use unicode_normalization::{is_nfc_quick, is_nfd_quick, is_nfkc_quick, is_nfkd_quick, IsNormalized};
+
+    let quick = match wrapper {
+        tk::NormalizerWrapper::NFC(_) => is_nfc_quick(str.chars()),
+        tk::NormalizerWrapper::NFD(_) => is_nfd_quick(str.chars()),
+        tk::NormalizerWrapper::NFKC(_) => is_nfkc_quick(str.chars()),
+        tk::NormalizerWrapper::NFKD(_) => is_nfkd_quick(str.chars()),
+        _ => return None,
+    };
+    match quick {
+        IsNormalized::Yes => Some(true),
+        IsNormalized::No => Some(false),
+        IsNormalized::Maybe => None,
+    }
+}
+
+fn is_normalized_up_to(normalizer: &Normalizer, str: &str) -> Result<usize> {
+    if quick_is_normalized(&normalizer.0, str) == Some(true) {
+        return Ok(str.len());
+    }
+    let mut normalized = NormalizedString(str.into());
+    normalizer.normalize(&mut normalized)?;
+    let result = normalized.get();
+    let mut matched = str
+        .as_bytes()
+        .iter()
+        .zip(result.as_bytes().iter())
+        .take_while(|(a, b)| a == b)
+        .count();
+    while matched > 0 && !str.is_char_boundary(matched) {
+        matched -= 1;
+    }
+    Ok(matched)
+}
+
+fn is_normalized(normalizer: &Normalizer, str: &str) -> Result<bool> {
+    if let Some(answer) = quick_is_normalized(&normalizer.0, str) {
+        return Ok(answer);
+    }
+    let mut normalized = NormalizedString(str.into());
+    normalizer.normalize(&mut normalized)?;
+    Ok(normalized.get() == str)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // "a𝄞b": 'a' (1 UTF-16 unit, 1 UTF-8 byte), then U+1D11E MUSICAL SYMBOL G CLEF, a non-BMP
+    // code point encoded as a surrogate pair in UTF-16 (2 units) and 4 bytes in UTF-8, then 'b'.
+    fn surrogate_pair_buffer() -> Vec<u16> {
+        "a\u{1D11E}b".encode_utf16().collect()
+    }
+
+    #[test]
+    fn utf16_offset_to_utf8_accounts_for_surrogate_pairs() {
+        let buf = surrogate_pair_buffer();
+        assert_eq!(utf16_offset_to_utf8(&buf, 0), 0);
+        assert_eq!(utf16_offset_to_utf8(&buf, 1), 1);
+        assert_eq!(utf16_offset_to_utf8(&buf, 3), 5);
+        assert_eq!(utf16_offset_to_utf8(&buf, 4), 6);
+    }
+
+    #[test]
+    fn utf8_offset_to_utf16_accounts_for_surrogate_pairs() {
+        let buf = surrogate_pair_buffer();
+        assert_eq!(utf8_offset_to_utf16(&buf, 0), 0);
+        assert_eq!(utf8_offset_to_utf16(&buf, 1), 1);
+        assert_eq!(utf8_offset_to_utf16(&buf, 5), 3);
+        assert_eq!(utf8_offset_to_utf16(&buf, 6), 4);
+    }
+
+    #[test]
+    fn utf16_utf8_offset_conversions_round_trip_through_surrogate_pairs() {
+        let buf = surrogate_pair_buffer();
+        for utf16_offset in 0..=buf.len() {
+            let utf8_offset = utf16_offset_to_utf8(&buf, utf16_offset);
+            assert_eq!(utf8_offset_to_utf16(&buf, utf8_offset), utf16_offset);
+        }
+    }
+}
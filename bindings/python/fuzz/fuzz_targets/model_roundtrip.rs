@@ -0,0 +1,115 @@
+#![no_main]
+
+use arbitrary::Arbitrary;
+use libfuzzer_sys::fuzz_target;
+use std::collections::HashMap;
+use tokenizers::models::bpe::BPE;
+use tokenizers::models::unigram::Unigram;
+use tokenizers::models::wordlevel::WordLevel;
+use tokenizers::models::wordpiece::WordPiece;
+use tokenizers::models::ModelWrapper;
+use tokenizers::Model;
+use tokenizers_python::models::PyModel;
+
+/// One-byte discriminant selecting which of the four `ModelWrapper` arms this run builds, so a
+/// single harness fuzzes the whole model layer instead of one target per model type.
+#[derive(Debug)]
+enum ModelKind {
+    Bpe,
+    WordPiece,
+    WordLevel,
+    Unigram,
+}
+
+impl<'a> Arbitrary<'a> for ModelKind {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(match u8::arbitrary(u)? % 4 {
+            0 => ModelKind::Bpe,
+            1 => ModelKind::WordPiece,
+            2 => ModelKind::WordLevel,
+            _ => ModelKind::Unigram,
+        })
+    }
+}
+
+#[derive(Arbitrary, Debug)]
+struct FuzzInput {
+    kind: ModelKind,
+    vocab: Vec<(String, u32)>,
+    merges: Vec<(String, String)>,
+    byte_fallback: bool,
+    tokenize_input: Vec<u8>,
+}
+
+fn build_model(input: &FuzzInput) -> Option<ModelWrapper> {
+    let vocab: HashMap<String, u32> = input.vocab.iter().cloned().collect();
+    match input.kind {
+        ModelKind::Bpe => {
+            let mut builder = BPE::builder();
+            if !vocab.is_empty() {
+                builder = builder.vocab_and_merges(vocab, input.merges.clone());
+            }
+            builder.build().ok().map(Into::into)
+        }
+        ModelKind::WordPiece => {
+            let mut builder = WordPiece::builder();
+            if !vocab.is_empty() {
+                builder = builder.vocab(vocab);
+            }
+            builder.build().ok().map(Into::into)
+        }
+        ModelKind::WordLevel => {
+            let mut builder = WordLevel::builder();
+            if !vocab.is_empty() {
+                builder = builder.vocab(vocab);
+            }
+            builder.build().ok().map(Into::into)
+        }
+        ModelKind::Unigram => {
+            if vocab.is_empty() {
+                Some(Unigram::default().into())
+            } else {
+                let scored: Vec<(String, f64)> =
+                    vocab.into_iter().map(|(token, id)| (token, id as f64)).collect();
+                Unigram::from(scored, None, input.byte_fallback).ok().map(Into::into)
+            }
+        }
+    }
+}
+
+fuzz_target!(|input: FuzzInput| {
+    let Some(wrapper) = build_model(&input) else {
+        return;
+    };
+    let model: PyModel = wrapper.into();
+
+    // Invariant 1: serde round-trip stability, mirroring the `__getstate__`/`__setstate__`
+    // pickle path. Reserializing to the same bytes isn't enough on its own: a rebuilt
+    // lookup index that silently came back empty would still reserialize identically,
+    // since it's `#[serde(skip)]`. So also check the round-tripped model still tokenizes
+    // the same way as the original.
+    let serialized = serde_json::to_string(&model).expect("model must always serialize");
+    let roundtripped: PyModel =
+        serde_json::from_str(&serialized).expect("serialized model must always deserialize");
+    let reserialized =
+        serde_json::to_string(&roundtripped).expect("round-tripped model must serialize");
+    assert_eq!(serialized, reserialized);
+
+    let text = String::from_utf8_lossy(&input.tokenize_input);
+    let original_guard = model.model.read().unwrap();
+    let original_tokens = original_guard.tokenize(&text).unwrap_or_default();
+    drop(original_guard);
+
+    // Invariant 2: tokenize must never panic on arbitrary (possibly invalid) UTF-8, and must
+    // behave identically before and after the round trip.
+    let guard = roundtripped.model.read().unwrap();
+    let tokens = guard.tokenize(&text).unwrap_or_default();
+    assert_eq!(tokens, original_tokens);
+
+    // Invariant 3: the id/token mapping must be self-consistent for every produced token.
+    for token in &tokens {
+        if let Some(id) = guard.token_to_id(&token.value) {
+            assert_eq!(guard.id_to_token(id).as_deref(), Some(token.value.as_str()));
+        }
+    }
+});
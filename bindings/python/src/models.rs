@@ -113,12 +113,11 @@ impl PyModel {
         }
     }
 
-    fn tokenize(&self, tokens: &str) -> PyResult<Vec<PyToken>> {
-        Ok(ToPyResult(self.model.read().unwrap().tokenize(tokens))
-            .into_py()?
-            .into_iter()
-            .map(|t| t.into())
-            .collect())
+    fn tokenize(&self, py: Python, tokens: &str) -> PyResult<Vec<PyToken>> {
+        let model = self.model.clone();
+        let tokens: PyResult<Vec<_>> =
+            py.allow_threads(|| ToPyResult(model.read().unwrap().tokenize(tokens)).into());
+        Ok(tokens?.into_iter().map(|t| t.into()).collect())
     }
 
     fn token_to_id(&self, token: &str) -> Option<u32> {
@@ -129,9 +128,13 @@ impl PyModel {
         self.model.read().unwrap().id_to_token(id)
     }
 
-    fn save(&self, folder: &str, name: Option<&str>) -> PyResult<Vec<String>> {
-        let saved: PyResult<Vec<_>> =
-            ToPyResult(self.model.read().unwrap().save(Path::new(folder), name)).into();
+    fn save(&self, py: Python, folder: &str, name: Option<&str>) -> PyResult<Vec<String>> {
+        let model = self.model.clone();
+        let folder = PathBuf::from(folder);
+        let name = name.map(str::to_string);
+        let saved: PyResult<Vec<_>> = py.allow_threads(|| {
+            ToPyResult(model.read().unwrap().save(&folder, name.as_deref())).into()
+        });
 
         Ok(saved?
             .into_iter()
@@ -589,26 +592,113 @@ impl PyWordLevel {
     }
 }
 
+/// Unigram Model
+/// Allows the creation of a Unigram Model to be used with a Tokenizer
 #[pyclass(extends=PyModel, module = "tokenizers.models", name=Unigram)]
 pub struct PyUnigram {}
 
 #[pymethods]
 impl PyUnigram {
+    #[getter]
+    fn get_vocab(self_: PyRef<Self>) -> Vec<(String, f64)> {
+        let super_ = self_.as_ref();
+        let model = super_.model.read().unwrap();
+        if let ModelWrapper::Unigram(ref unigram) = *model {
+            unigram.vocab().to_vec()
+        } else {
+            unreachable!()
+        }
+    }
+
+    #[setter]
+    fn set_vocab(self_: PyRef<Self>, vocab: Vec<(String, f64)>) {
+        let super_ = self_.as_ref();
+        let mut model = super_.model.write().unwrap();
+        if let ModelWrapper::Unigram(ref mut unigram) = *model {
+            unigram.set_vocab(vocab);
+        }
+    }
+
+    #[getter]
+    fn get_unk_id(self_: PyRef<Self>) -> Option<usize> {
+        let super_ = self_.as_ref();
+        let model = super_.model.read().unwrap();
+        if let ModelWrapper::Unigram(ref unigram) = *model {
+            unigram.unk_id
+        } else {
+            unreachable!()
+        }
+    }
+
+    #[setter]
+    fn set_unk_id(self_: PyRef<Self>, unk_id: Option<usize>) {
+        let super_ = self_.as_ref();
+        let mut model = super_.model.write().unwrap();
+        if let ModelWrapper::Unigram(ref mut unigram) = *model {
+            unigram.unk_id = unk_id;
+        }
+    }
+
+    #[getter]
+    fn get_byte_fallback(self_: PyRef<Self>) -> bool {
+        let super_ = self_.as_ref();
+        let model = super_.model.read().unwrap();
+        if let ModelWrapper::Unigram(ref unigram) = *model {
+            unigram.byte_fallback
+        } else {
+            unreachable!()
+        }
+    }
+
+    #[setter]
+    fn set_byte_fallback(self_: PyRef<Self>, byte_fallback: bool) {
+        let super_ = self_.as_ref();
+        let mut model = super_.model.write().unwrap();
+        if let ModelWrapper::Unigram(ref mut unigram) = *model {
+            unigram.set_byte_fallback(byte_fallback);
+        }
+    }
+
     #[new]
-    fn new(vocab: Option<Vec<(String, f64)>>, unk_id: Option<usize>) -> PyResult<(Self, PyModel)> {
+    #[args(byte_fallback = "false")]
+    fn new(
+        vocab: Option<Vec<(String, f64)>>,
+        unk_id: Option<usize>,
+        byte_fallback: bool,
+    ) -> PyResult<(Self, PyModel)> {
         match (vocab, unk_id) {
             (Some(vocab), unk_id) => {
-                let model = Unigram::from(vocab, unk_id).map_err(|e| {
+                let model = Unigram::from(vocab, unk_id, byte_fallback).map_err(|e| {
                     exceptions::PyException::new_err(format!("Error while loading Unigram: {}", e))
                 })?;
                 Ok((PyUnigram {}, model.into()))
             }
-            (None, None) => Ok((PyUnigram {}, Unigram::default().into())),
+            (None, None) => {
+                let mut model = Unigram::default();
+                model.set_byte_fallback(byte_fallback);
+                Ok((PyUnigram {}, model.into()))
+            }
             _ => Err(exceptions::PyValueError::new_err(
                 "`vocab` and `unk_id` must be both specified",
             )),
         }
     }
+
+    #[staticmethod]
+    fn read_file(vocab_filename: &str) -> PyResult<Vec<(String, f64)>> {
+        Unigram::read_file(vocab_filename).map_err(|e| {
+            exceptions::PyValueError::new_err(format!("Error while reading Unigram file: {}", e))
+        })
+    }
+
+    #[staticmethod]
+    #[args(byte_fallback = "false")]
+    fn from_file(py: Python, vocab_filename: &str, byte_fallback: bool) -> PyResult<Py<Self>> {
+        let vocab = Unigram::read_file(vocab_filename).map_err(|e| {
+            exceptions::PyValueError::new_err(format!("Error while reading Unigram file: {}", e))
+        })?;
+        Py::new(py, PyUnigram::new(Some(vocab), None, byte_fallback)?)
+    }
 }
 
 #[cfg(test)]
@@ -616,6 +706,7 @@ mod test {
     use crate::models::PyModel;
     use pyo3::prelude::*;
     use tk::models::bpe::BPE;
+    use tk::models::unigram::Unigram;
     use tk::models::ModelWrapper;
 
     #[test]
@@ -653,4 +744,45 @@ mod test {
             _ => panic!("Expected Bert postprocessor."),
         };
     }
+
+    #[test]
+    fn unigram_byte_fallback_round_trips_vocab_and_unk_id() {
+        let vocab = vec![("<unk>".to_string(), 0.0), ("a".to_string(), -1.0)];
+        let model = Unigram::from(vocab.clone(), Some(0), true).unwrap();
+        let py_model = PyModel::from(model);
+
+        if let ModelWrapper::Unigram(ref unigram) = *py_model.model.as_ref().read().unwrap() {
+            assert_eq!(unigram.unk_id, Some(0));
+            assert!(unigram.byte_fallback);
+            assert!(unigram.vocab().iter().take(2).eq(vocab.iter()));
+            assert!(unigram.vocab().iter().any(|(token, _)| token == "<0x00>"));
+        } else {
+            panic!("Expected Unigram model.");
+        }
+    }
+
+    #[test]
+    fn unigram_set_byte_fallback_adds_byte_tokens_to_an_existing_model() {
+        let vocab = vec![("<unk>".to_string(), 0.0), ("a".to_string(), -1.0)];
+        let mut model = Unigram::from(vocab, Some(0), false).unwrap();
+        model.set_byte_fallback(true);
+
+        assert!(model.vocab().iter().any(|(token, _)| token == "<0x00>"));
+        assert!(model.vocab().iter().any(|(token, _)| token == "<0xFF>"));
+    }
+
+    #[test]
+    fn unigram_read_file_parses_token_score_pairs() {
+        let dir = std::env::temp_dir();
+        let path = dir.join("tokenizers-test-unigram-read-file.txt");
+        std::fs::write(&path, "<unk>\t0\na\t-1.5\n").unwrap();
+
+        let vocab = Unigram::read_file(path.to_str().unwrap()).unwrap();
+
+        assert_eq!(
+            vocab,
+            vec![("<unk>".to_string(), 0.0), ("a".to_string(), -1.5)]
+        );
+        std::fs::remove_file(&path).unwrap();
+    }
 }
@@ -0,0 +1,4 @@
+mod model;
+mod serialization;
+
+pub use model::Unigram;
@@ -0,0 +1,327 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{Model, Result, Token};
+
+fn byte_fallback_token(byte: u8) -> String {
+    format!("<0x{:02X}>", byte)
+}
+
+/// A unigram language-model tokenizer, as used by SentencePiece.
+///
+/// Segmentation runs a Viterbi search over the lattice of every substring present in
+/// `vocab`, picking the path whose per-token scores sum to the highest total rather than
+/// greedily taking the longest match at each position: a shorter piece can win if pairing it
+/// with its neighbours scores higher overall than the single longer piece would. A character
+/// with no path through `vocab` at all is scored at the lowest score anywhere in `vocab`, so a
+/// real match is always preferred when one exists; with `byte_fallback` enabled its UTF-8
+/// bytes are each emitted as an individual `<0xXX>` token (which `from`/`set_byte_fallback`
+/// add to the vocabulary) instead of collapsing the whole piece to the
+/// single `unk` token.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(from = "UnigramSerde")]
+pub struct Unigram {
+    pub(crate) vocab: Vec<(String, f64)>,
+    #[serde(skip)]
+    token_to_ids: HashMap<String, u32>,
+    pub unk_id: Option<usize>,
+    pub byte_fallback: bool,
+}
+
+/// Plain shape of a serialized `Unigram`. `token_to_ids` is never serialized (it's rebuilt
+/// from `vocab`), so deserializing needs this intermediate to rebuild it rather than leaving
+/// it empty the way a derived `Deserialize` would with `#[serde(skip)]` alone.
+#[derive(Deserialize)]
+struct UnigramSerde {
+    vocab: Vec<(String, f64)>,
+    unk_id: Option<usize>,
+    byte_fallback: bool,
+}
+
+impl From<UnigramSerde> for Unigram {
+    fn from(data: UnigramSerde) -> Self {
+        Unigram::from(data.vocab, data.unk_id, data.byte_fallback).unwrap()
+    }
+}
+
+impl Default for Unigram {
+    fn default() -> Self {
+        Unigram::from(vec![("<unk>".to_string(), 0.0)], Some(0), false).unwrap()
+    }
+}
+
+impl Unigram {
+    pub fn from(
+        vocab: Vec<(String, f64)>,
+        unk_id: Option<usize>,
+        byte_fallback: bool,
+    ) -> Result<Self> {
+        let token_to_ids = vocab
+            .iter()
+            .enumerate()
+            .map(|(id, (token, _))| (token.clone(), id as u32))
+            .collect();
+
+        let mut model = Self {
+            vocab,
+            token_to_ids,
+            unk_id,
+            byte_fallback: false,
+        };
+        if byte_fallback {
+            model.set_byte_fallback(true);
+        }
+        Ok(model)
+    }
+
+    /// Returns the model's vocabulary, as `(token, score)` pairs.
+    pub fn vocab(&self) -> &[(String, f64)] {
+        &self.vocab
+    }
+
+    /// Replaces the model's vocabulary, rebuilding the `token -> id` index to match. If
+    /// `byte_fallback` is already enabled, re-adds the `<0xXX>` tokens the new vocabulary
+    /// doesn't have yet, so byte-fallback doesn't silently stop working after this call.
+    pub fn set_vocab(&mut self, vocab: Vec<(String, f64)>) {
+        self.token_to_ids = vocab
+            .iter()
+            .enumerate()
+            .map(|(id, (token, _))| (token.clone(), id as u32))
+            .collect();
+        self.vocab = vocab;
+        if self.byte_fallback {
+            self.add_byte_fallback_tokens();
+        }
+    }
+
+    /// Turns byte-fallback on or off. Turning it on for the first time extends `vocab` with
+    /// the 256 `<0xXX>` tokens `byte_fallback_tokens` looks up, the same way `from` does when
+    /// constructing a model with `byte_fallback` already set.
+    pub fn set_byte_fallback(&mut self, byte_fallback: bool) {
+        if byte_fallback && !self.byte_fallback {
+            self.add_byte_fallback_tokens();
+        }
+        self.byte_fallback = byte_fallback;
+    }
+
+    /// Appends any of the 256 `<0xXX>` tokens not already in `vocab`, scored at the lowest
+    /// score anywhere in `vocab`.
+    fn add_byte_fallback_tokens(&mut self) {
+        let min_score = self
+            .vocab
+            .iter()
+            .map(|(_, score)| *score)
+            .fold(f64::INFINITY, f64::min);
+        let min_score = if min_score.is_finite() { min_score } else { 0.0 };
+        for byte in 0..=u8::MAX {
+            let token = byte_fallback_token(byte);
+            if !self.token_to_ids.contains_key(&token) {
+                self.token_to_ids
+                    .insert(token.clone(), self.vocab.len() as u32);
+                self.vocab.push((token, min_score));
+            }
+        }
+    }
+
+    fn byte_fallback_tokens(&self, piece: &str, offset: usize) -> Option<Vec<Token>> {
+        if !self.byte_fallback {
+            return None;
+        }
+        piece
+            .bytes()
+            .enumerate()
+            .map(|(i, byte)| {
+                let token = byte_fallback_token(byte);
+                self.token_to_ids
+                    .get(&token)
+                    .map(|&id| Token::new(id, token, (offset + i, offset + i + 1)))
+            })
+            .collect()
+    }
+
+    fn unk_token(&self, piece: &str, offset: usize) -> Token {
+        let unk_id = self.unk_id.unwrap_or(0);
+        let value = self
+            .vocab
+            .get(unk_id)
+            .map(|(token, _)| token.clone())
+            .unwrap_or_else(|| "<unk>".to_string());
+        Token::new(unk_id as u32, value, (offset, offset + piece.len()))
+    }
+}
+
+impl Model for Unigram {
+    fn tokenize(&self, sequence: &str) -> Result<Vec<Token>> {
+        let char_starts: Vec<usize> = sequence.char_indices().map(|(i, _)| i).collect();
+        let n = char_starts.len();
+        if n == 0 {
+            return Ok(Vec::new());
+        }
+        let char_end = |i: usize| char_starts.get(i).copied().unwrap_or(sequence.len());
+
+        // A character with no match anywhere in `vocab` is scored at the worst score in
+        // `vocab`, so the Viterbi search below always prefers a real match when one exists.
+        let fallback_score = self
+            .vocab
+            .iter()
+            .map(|(_, score)| *score)
+            .fold(f64::INFINITY, f64::min);
+        let fallback_score = if fallback_score.is_finite() {
+            fallback_score
+        } else {
+            0.0
+        };
+
+        enum Edge {
+            Piece { id: u32, value: String },
+            Fallback,
+        }
+
+        // best_score[i] is the highest-scoring segmentation of sequence[..char_end(i)]; back[i]
+        // records which edge achieves it so the winning path (not just the longest match at
+        // each position) can be recovered afterwards.
+        let mut best_score = vec![f64::NEG_INFINITY; n + 1];
+        let mut back: Vec<Option<(usize, Edge)>> = (0..=n).map(|_| None).collect();
+        best_score[0] = 0.0;
+
+        for i in 1..=n {
+            let end = char_end(i);
+            for j in (0..i).rev() {
+                if !best_score[j].is_finite() {
+                    continue;
+                }
+                let piece = &sequence[char_starts[j]..end];
+                if let Some(&id) = self.token_to_ids.get(piece) {
+                    let (_, score) = self.vocab[id as usize];
+                    let candidate = best_score[j] + score;
+                    if candidate > best_score[i] {
+                        best_score[i] = candidate;
+                        back[i] = Some((
+                            j,
+                            Edge::Piece {
+                                id,
+                                value: piece.to_string(),
+                            },
+                        ));
+                    }
+                }
+            }
+            // A lone character that matched nothing above still needs an edge so the lattice
+            // stays fully connected; it competes with real matches on the same footing.
+            if best_score[i - 1].is_finite() {
+                let candidate = best_score[i - 1] + fallback_score;
+                if candidate > best_score[i] {
+                    best_score[i] = candidate;
+                    back[i] = Some((i - 1, Edge::Fallback));
+                }
+            }
+        }
+
+        let mut path = Vec::new();
+        let mut i = n;
+        while i > 0 {
+            let (j, edge) = back[i].take().expect("lattice is connected by single-char edges");
+            path.push((j, i, edge));
+            i = j;
+        }
+        path.reverse();
+
+        let mut tokens = Vec::new();
+        for (j, i, edge) in path {
+            let start = char_starts[j];
+            let end = char_end(i);
+            match edge {
+                Edge::Piece { id, value } => tokens.push(Token::new(id, value, (start, end))),
+                Edge::Fallback => {
+                    let piece = &sequence[start..end];
+                    match self.byte_fallback_tokens(piece, start) {
+                        Some(byte_tokens) => tokens.extend(byte_tokens),
+                        None => tokens.push(self.unk_token(piece, start)),
+                    }
+                }
+            }
+        }
+        Ok(tokens)
+    }
+
+    fn token_to_id(&self, token: &str) -> Option<u32> {
+        self.token_to_ids.get(token).copied()
+    }
+
+    fn id_to_token(&self, id: u32) -> Option<String> {
+        self.vocab.get(id as usize).map(|(token, _)| token.clone())
+    }
+
+    fn get_vocab(&self) -> HashMap<String, u32> {
+        self.token_to_ids.clone()
+    }
+
+    fn get_vocab_size(&self) -> usize {
+        self.vocab.len()
+    }
+
+    fn save(&self, folder: &Path, name: Option<&str>) -> Result<Vec<PathBuf>> {
+        let file_name = match name {
+            Some(name) => format!("{}-unigram.json", name),
+            None => "unigram.json".to_string(),
+        };
+        let path = folder.join(file_name);
+        std::fs::write(&path, serde_json::to_string(self)?)?;
+        Ok(vec![path])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn byte_fallback_emits_per_byte_tokens_instead_of_unk() {
+        let model = Unigram::from(vec![("<unk>".to_string(), 0.0)], Some(0), true).unwrap();
+
+        let tokens = model.tokenize("é").unwrap();
+
+        // "é" (U+00E9) isn't in the vocab on its own, so with byte_fallback on, it must come
+        // back as its two UTF-8 bytes instead of collapsing to the single `<unk>` token.
+        assert_eq!(tokens.len(), 2);
+        assert_eq!(tokens[0].value, "<0xC3>");
+        assert_eq!(tokens[1].value, "<0xA9>");
+        assert_eq!(tokens[0].offsets, (0, 1));
+        assert_eq!(tokens[1].offsets, (1, 2));
+    }
+
+    #[test]
+    fn without_byte_fallback_unknown_piece_collapses_to_unk() {
+        let model = Unigram::from(vec![("<unk>".to_string(), 0.0)], Some(0), false).unwrap();
+
+        let tokens = model.tokenize("é").unwrap();
+
+        assert_eq!(tokens.len(), 1);
+        assert_eq!(tokens[0].value, "<unk>");
+    }
+
+    #[test]
+    fn segmentation_prefers_the_highest_scoring_path_over_the_longest_match() {
+        // "a" + "b" (-1.0 + -1.0 = -2.0) outscores the single longer piece "ab" (-5.0), so the
+        // winning segmentation should split "ab" even though "ab" matches greedily at position 0.
+        let model = Unigram::from(
+            vec![
+                ("<unk>".to_string(), 0.0),
+                ("a".to_string(), -1.0),
+                ("b".to_string(), -1.0),
+                ("ab".to_string(), -5.0),
+            ],
+            Some(0),
+            false,
+        )
+        .unwrap();
+
+        let tokens = model.tokenize("ab").unwrap();
+
+        assert_eq!(tokens.len(), 2);
+        assert_eq!(tokens[0].value, "a");
+        assert_eq!(tokens[1].value, "b");
+    }
+}
@@ -0,0 +1,27 @@
+use std::fs::read_to_string;
+
+use super::Unigram;
+use crate::Result;
+
+impl Unigram {
+    /// Reads a Unigram vocabulary file (one `token\tscore` pair per line, as written out by
+    /// SentencePiece-style training) into the same `Vec<(String, f64)>` shape the
+    /// `(vocab, unk_id, byte_fallback)` constructor expects. This is not the JSON format
+    /// `Unigram::save` writes.
+    pub fn read_file(vocab_filename: &str) -> Result<Vec<(String, f64)>> {
+        let content = read_to_string(vocab_filename)?;
+        content
+            .lines()
+            .filter(|line| !line.is_empty())
+            .map(|line| {
+                let (token, score) = line
+                    .rsplit_once('\t')
+                    .ok_or_else(|| format!("Malformed unigram vocab line: {}", line))?;
+                let score: f64 = score
+                    .parse()
+                    .map_err(|_| format!("Malformed unigram vocab score in line: {}", line))?;
+                Ok((token.to_string(), score))
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,14 @@
+use crate::{NormalizedString, Normalizer, Result};
+use serde::{Deserialize, Serialize};
+use unicode_categories::UnicodeCategories;
+
+/// Removes Unicode control (Cc) and format (Cf) characters.
+#[derive(Copy, Clone, Debug, Default, Serialize, Deserialize)]
+pub struct ControlChar;
+
+impl Normalizer for ControlChar {
+    fn normalize(&self, normalized: &mut NormalizedString) -> Result<()> {
+        normalized.filter(|c| !(c.is_other_control() || c.is_other_format()));
+        Ok(())
+    }
+}
@@ -0,0 +1,14 @@
+mod control_char;
+mod nonspacing_mark;
+mod russian;
+mod swedish_recomposition;
+
+pub use control_char::ControlChar;
+pub use nonspacing_mark::NonspacingMark;
+pub use russian::Russian;
+pub use swedish_recomposition::SwedishRecomposition;
+
+// `NormalizerWrapper` (defined alongside the other normalizer variants in this module) gains
+// matching `ControlChar(ControlChar)` / `NonspacingMark(NonspacingMark)` / `Russian(Russian)` /
+// `SwedishRecomposition(SwedishRecomposition)` arms so `sequence_normalizer` can compose them
+// with the rest of the built-in normalizers.
@@ -0,0 +1,14 @@
+use crate::{NormalizedString, Normalizer, Result};
+use serde::{Deserialize, Serialize};
+use unicode_categories::UnicodeCategories;
+
+/// Strips nonspacing combining marks (category Mn).
+#[derive(Copy, Clone, Debug, Default, Serialize, Deserialize)]
+pub struct NonspacingMark;
+
+impl Normalizer for NonspacingMark {
+    fn normalize(&self, normalized: &mut NormalizedString) -> Result<()> {
+        normalized.filter(|c| !c.is_mark_nonspacing());
+        Ok(())
+    }
+}
@@ -0,0 +1,17 @@
+use crate::{NormalizedString, Normalizer, Result};
+use serde::{Deserialize, Serialize};
+
+/// Folds the Russian letters `ё`/`Ё` to `е`/`Е`.
+#[derive(Copy, Clone, Debug, Default, Serialize, Deserialize)]
+pub struct Russian;
+
+impl Normalizer for Russian {
+    fn normalize(&self, normalized: &mut NormalizedString) -> Result<()> {
+        normalized.map(|c| match c {
+            'ё' => 'е',
+            'Ё' => 'Е',
+            _ => c,
+        });
+        Ok(())
+    }
+}
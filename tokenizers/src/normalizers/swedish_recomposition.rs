@@ -0,0 +1,61 @@
+use crate::{NormalizedString, Normalizer, Result};
+use serde::{Deserialize, Serialize};
+
+/// Recomposes base+combining-mark sequences that spell out the Swedish letters å/ä/ö into their
+/// precomposed code points.
+#[derive(Copy, Clone, Debug, Default, Serialize, Deserialize)]
+pub struct SwedishRecomposition;
+
+impl Normalizer for SwedishRecomposition {
+    fn normalize(&self, normalized: &mut NormalizedString) -> Result<()> {
+        let text = normalized.get().to_string();
+        let mut dest = Vec::with_capacity(text.chars().count());
+        let mut chars = text.chars().peekable();
+        while let Some(base) = chars.next() {
+            match chars.peek().and_then(|&mark| recompose(base, mark)) {
+                Some(precomposed) => {
+                    chars.next();
+                    // This new char absorbs the combining mark that followed it, so it maps
+                    // back to two original chars instead of one.
+                    dest.push((precomposed, -1));
+                }
+                None => dest.push((base, 0)),
+            }
+        }
+        normalized.transform_range(0..text.len(), dest, 0);
+        Ok(())
+    }
+}
+
+fn recompose(base: char, mark: char) -> Option<char> {
+    match (base, mark) {
+        ('a', '\u{030A}') => Some('å'),
+        ('A', '\u{030A}') => Some('Å'),
+        ('a', '\u{0308}') => Some('ä'),
+        ('A', '\u{0308}') => Some('Ä'),
+        ('o', '\u{0308}') => Some('ö'),
+        ('O', '\u{0308}') => Some('Ö'),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn recomposed_span_maps_back_to_its_original_bytes() {
+        let mut normalized = NormalizedString::from("a\u{030A}bc");
+        SwedishRecomposition.normalize(&mut normalized).unwrap();
+
+        assert_eq!(normalized.get(), "åbc");
+
+        // "bc" starts right after the precomposed "å" in the normalized text (byte 2..4), but
+        // in the original text it starts after the two-char "a\u{030A}" sequence (byte 3..5).
+        let original_range = normalized.range_original(2..4).unwrap();
+        assert_eq!(original_range, 3..5);
+
+        let normalized_range = normalized.range_normalized(3..5).unwrap();
+        assert_eq!(normalized_range, 2..4);
+    }
+}